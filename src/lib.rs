@@ -1,8 +1,11 @@
 pub enum TokenCategory {
     Sequence,
     Alphanumeric,
+    NumberLiteral,
     Whitespace,
     StringLiteral,
+    CharLiteral,
+    Comment,
     Symbol,
     Unknown,
 }
@@ -15,75 +18,281 @@ pub struct Token<'a> {
     pub row: usize,
     pub col: usize,
     pub category: TokenCategory,
+    config: &'a Tokenizer,
 }
 
-fn get_sequence(s: &str) -> Option<&'static str> {
-    let sequences = vec![
-        "===", "<=", ">=", "!=", "==", "->", "=>", "*=", "+=", "/=", "%=", "::",
-    ];
-    for sequence in sequences {
-        if s.starts_with(sequence) {
-            return Some(sequence);
-        }
+/// A lazy iterator over the tokens of a buffer.
+///
+/// Produced by [`Token::tokens`], it pulls one [`Token::next_pair`] at a time
+/// so callers can stream without materializing the whole sequence up front.
+pub struct Tokens<'a> {
+    remainder: Option<Token<'a>>,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(self: &mut Tokens<'a>) -> Option<Token<'a>> {
+        let current = self.remainder.take()?;
+        let (token, remainder) = current.next_pair();
+        self.remainder = remainder;
+        return Some(token);
+    }
+}
+
+/// Holds every rule the tokenizer needs that is not fixed by the language of
+/// Rust itself: the multi-character `sequences` (tried in the order given; the
+/// default list is sorted longest-first so `===` wins over `==`, a custom list
+/// is used as it is), the single-character `symbols`, the quote characters used
+/// for string and character literals, and the line/block comment delimiters.
+///
+/// The [`Default`] configuration matches the historical C/CFEngine-ish dialect
+/// the crate shipped with. Build a custom one to tokenize other languages
+/// without forking, then feed it to [`Token::from_with`].
+pub struct Tokenizer {
+    pub sequences: Vec<String>,
+    pub symbols: String,
+    pub string_quotes: Vec<char>,
+    pub char_quotes: Vec<char>,
+    pub line_comments: Vec<String>,
+    pub block_comments: Vec<(String, String)>,
+}
+
+impl Default for Tokenizer {
+    fn default() -> Tokenizer {
+        let mut sequences: Vec<String> = vec![
+            "===", "<=", ">=", "!=", "==", "->", "=>", "*=", "+=", "/=", "%=", "::",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        sort_longest_first(&mut sequences);
+        return Tokenizer {
+            sequences,
+            symbols: String::from("(){}<>[]:;,.@!/\\|-+=*?&%$#"),
+            string_quotes: vec!['\'', '\"'],
+            char_quotes: vec!['\''],
+            line_comments: vec![String::from("//"), String::from("#")],
+            block_comments: vec![(String::from("/*"), String::from("*/"))],
+        };
     }
-    return None;
 }
 
-fn find_first_token(s: &str) -> (TokenCategory, usize) {
-    match get_sequence(s) {
-        Some(seq) => {
-            return (TokenCategory::Sequence, seq.len());
+// Sorts sequences so longer ones are tried first, ensuring the longest match
+// (`===`) wins over a prefix of it (`==`).
+fn sort_longest_first(sequences: &mut [String]) {
+    sequences.sort_by(|a, b| b.len().cmp(&a.len()));
+}
+
+// The default configuration, allocated once and shared by the `Token::from`
+// family so the common case needs no explicit `Tokenizer`.
+fn default_tokenizer() -> &'static Tokenizer {
+    use std::sync::OnceLock;
+    static DEFAULT: OnceLock<Tokenizer> = OnceLock::new();
+    return DEFAULT.get_or_init(Tokenizer::default);
+}
+
+impl Tokenizer {
+    fn get_sequence<'b>(self: &Tokenizer, s: &'b str) -> Option<&'b str> {
+        for sequence in &self.sequences {
+            if s.starts_with(sequence.as_str()) {
+                return Some(&s[..sequence.len()]);
+            }
         }
-        None => {}
-    };
-    let first = s.chars().nth(0).expect("Empty token!");
-    let length = s.len();
-    if is_alphanumeric(first) {
-        let len = match s.find(|c: char| !is_alphanumeric(c)) {
-            Some(n) => n,
-            None => length,
+        return None;
+    }
+
+    fn is_symbol(self: &Tokenizer, c: char) -> bool {
+        return self.symbols.contains(c);
+    }
+
+    fn is_quote(self: &Tokenizer, c: char) -> bool {
+        return self.string_quotes.contains(&c) || self.char_quotes.contains(&c);
+    }
+
+    // Recognizes a comment at the start of `s`. Line comments run through but
+    // not including the next `\n`; block comments run through their closing
+    // delimiter, keeping any interior newlines in the span so the following
+    // token's `row`/`col` stay correct. An unterminated block comment is
+    // returned as `Unknown` rather than panicking.
+    fn scan_comment(self: &Tokenizer, s: &str) -> Option<(TokenCategory, usize)> {
+        for prefix in &self.line_comments {
+            if s.starts_with(prefix.as_str()) {
+                let len = match s.find('\n') {
+                    Some(n) => n,
+                    None => s.len(),
+                };
+                return Some((TokenCategory::Comment, len));
+            }
+        }
+        for (open, close) in &self.block_comments {
+            if s.starts_with(open.as_str()) {
+                return Some(match s[open.len()..].find(close.as_str()) {
+                    Some(n) => (TokenCategory::Comment, open.len() + n + close.len()),
+                    None => (TokenCategory::Unknown, s.len()),
+                });
+            }
+        }
+        return None;
+    }
+
+    // Scans a quoted literal starting at `s[0]`, which is the `quote` character.
+    // A backslash escapes the following character, so `\"`, `\\` and `\n` do not
+    // terminate the literal; scanning stops at the first unescaped `quote`. A
+    // literal opened by a char quote and holding exactly one (possibly escaped)
+    // character is reported as `CharLiteral`, anything else as `StringLiteral`.
+    // When no closing quote is found before the end of the buffer the partial
+    // span is returned as `Unknown` rather than panicking.
+    fn scan_string(self: &Tokenizer, s: &str, quote: char) -> (TokenCategory, usize) {
+        let mut chars = s.char_indices();
+        chars.next();
+        let mut escaped = false;
+        for (i, c) in chars {
+            if escaped {
+                escaped = false;
+                continue;
+            }
+            if c == '\\' {
+                escaped = true;
+                continue;
+            }
+            if c == quote {
+                let len = i + c.len_utf8();
+                let inner = &s[quote.len_utf8()..i];
+                let category = if self.char_quotes.contains(&quote) && logical_len(inner) == 1 {
+                    TokenCategory::CharLiteral
+                } else {
+                    TokenCategory::StringLiteral
+                };
+                return (category, len);
+            }
+        }
+        return (TokenCategory::Unknown, s.len());
+    }
+
+    fn find_first_token(self: &Tokenizer, s: &str) -> (TokenCategory, usize) {
+        match self.scan_comment(s) {
+            Some(result) => {
+                return result;
+            }
+            None => {}
         };
-        return (TokenCategory::Alphanumeric, len);
-    }
-    if is_symbol(first) {
-        return (TokenCategory::Symbol, 1);
-    }
-    let category = match first {
-        ' ' => TokenCategory::Whitespace,
-        '\n' => TokenCategory::Whitespace,
-        '\t' => TokenCategory::Whitespace,
-        '\'' => TokenCategory::StringLiteral,
-        '\"' => TokenCategory::StringLiteral,
-        _ => panic!(),
-    };
-    let length = match category {
-        TokenCategory::Alphanumeric => match s.find(|c: char| !is_alphanumeric(c)) {
-            Some(n) => n,
-            None => length,
-        },
-        TokenCategory::Whitespace => match s.find(|c: char| c != first) {
-            Some(n) => n,
-            None => length,
-        },
-        TokenCategory::StringLiteral => {
-            let close = s.match_indices(first).nth(1).unwrap().0;
-            close + 2 * "'".len() - 1
+        match self.get_sequence(s) {
+            Some(seq) => {
+                return (TokenCategory::Sequence, seq.len());
+            }
+            None => {}
+        };
+        let first = s.chars().nth(0).expect("Empty token!");
+        let length = s.len();
+        if first.is_ascii_digit() {
+            return (TokenCategory::NumberLiteral, scan_number(s));
         }
-        TokenCategory::Symbol => 1,
-        _ => panic!(),
-    };
+        if is_alphanumeric(first) {
+            let len = match s.find(|c: char| !is_alphanumeric(c)) {
+                Some(n) => n,
+                None => length,
+            };
+            return (TokenCategory::Alphanumeric, len);
+        }
+        if self.is_symbol(first) {
+            return (TokenCategory::Symbol, 1);
+        }
+        if self.is_quote(first) {
+            return self.scan_string(s, first);
+        }
+        if first.is_whitespace() {
+            let len = match s.find(|c: char| c != first) {
+                Some(n) => n,
+                None => length,
+            };
+            return (TokenCategory::Whitespace, len);
+        }
+        panic!()
+    }
 
-    return (category, length);
+    /// Returns the [`TokenCategory`] of the first token in `s`.
+    pub fn token_category(self: &Tokenizer, s: &str) -> TokenCategory {
+        return self.find_first_token(s).0;
+    }
 }
 
-fn is_alphanumeric(c: char) -> bool {
-    let alphabet = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
-    return alphabet.contains(c);
+// Scans a numeric literal at the start of `s`, which is assumed to begin with
+// an ASCII digit. Recognizes `0x`/`0b`/`0o` prefixed integers as well as
+// decimals with at most one interior `.`, digit separators (`_`), and an
+// optional `[eE][+-]?digits` exponent. Returns the byte length of the literal;
+// every consumed character is ASCII, so the offset is always a char boundary.
+fn scan_number(s: &str) -> usize {
+    let b = s.as_bytes();
+    let n = b.len();
+
+    if n >= 2 && b[0] == b'0' {
+        let is_digit: Option<fn(u8) -> bool> = match b[1] {
+            b'x' | b'X' => Some(|c| c.is_ascii_hexdigit()),
+            b'b' | b'B' => Some(|c| c == b'0' || c == b'1'),
+            b'o' | b'O' => Some(|c| (b'0'..=b'7').contains(&c)),
+            _ => None,
+        };
+        if let Some(is_digit) = is_digit {
+            let mut i = 2;
+            while i < n && (is_digit(b[i]) || b[i] == b'_') {
+                i += 1;
+            }
+            if i > 2 {
+                return i;
+            }
+        }
+    }
+
+    let mut i = 0;
+    while i < n && (b[i].is_ascii_digit() || b[i] == b'_') {
+        i += 1;
+    }
+    if i + 1 < n && b[i] == b'.' && b[i + 1].is_ascii_digit() {
+        i += 1;
+        while i < n && (b[i].is_ascii_digit() || b[i] == b'_') {
+            i += 1;
+        }
+    }
+    if i < n && (b[i] == b'e' || b[i] == b'E') {
+        let mut j = i + 1;
+        if j < n && (b[j] == b'+' || b[j] == b'-') {
+            j += 1;
+        }
+        if j < n && b[j].is_ascii_digit() {
+            j += 1;
+            while j < n && (b[j].is_ascii_digit() || b[j] == b'_') {
+                j += 1;
+            }
+            i = j;
+        }
+    }
+    return i;
+}
+
+// Counts the logical characters in the content of a literal, treating a
+// backslash escape and the character it escapes as one.
+fn logical_len(inner: &str) -> usize {
+    let mut count = 0;
+    let mut escaped = false;
+    for c in inner.chars() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+            continue;
+        }
+        count += 1;
+    }
+    return count;
 }
 
-fn is_symbol(c: char) -> bool {
-    let symbols = "(){}<>[]:;,.@!/\\|-+=*?&%$#";
-    return symbols.contains(c);
+fn is_alphanumeric(c: char) -> bool {
+    // Accept any Unicode letter or digit (and `_`) so that non-ASCII
+    // identifiers like `Việt` or `中华` tokenize as a single word. `find`
+    // reports byte offsets, so the resulting lengths stay valid `split_at`
+    // boundaries even for multi-byte characters.
+    return c == '_' || c.is_alphanumeric();
 }
 
 fn get_line(string: &str) -> &str {
@@ -95,7 +304,7 @@ fn get_line(string: &str) -> &str {
 
 impl TokenCategory {
     pub fn from(s: &str) -> TokenCategory {
-        find_first_token(s).0
+        default_tokenizer().find_first_token(s).0
     }
 }
 
@@ -121,6 +330,24 @@ impl<'a> Token<'a> {
     /// assert_eq!(token.index, 0);
     /// ```
     pub fn from(string: &str) -> Token {
+        return Token::from_with(string, default_tokenizer());
+    }
+
+    /// Converts a `&str` into a `Token` using a custom [`Tokenizer`]
+    /// configuration. The configuration is threaded through the produced
+    /// token and every token derived from it, so alternative dialects stay
+    /// consistent across `split_at`/`next_pair`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let config = tokens::Tokenizer::default();
+    /// let token = tokens::Token::from_with("ab\n", &config);
+    ///
+    /// assert_eq!(token.string, "ab\n");
+    /// assert_eq!(token.index, 0);
+    /// ```
+    pub fn from_with(string: &'a str, config: &'a Tokenizer) -> Token<'a> {
         let token = Token {
             string: string,
             buffer: string,
@@ -128,7 +355,8 @@ impl<'a> Token<'a> {
             index: 0,
             row: 0,
             col: 0,
-            category: TokenCategory::from(string),
+            category: config.find_first_token(string).0,
+            config: config,
         };
         token.assertions();
         return token;
@@ -185,6 +413,7 @@ impl<'a> Token<'a> {
             row: self.row,
             col: self.col,
             category: self.category,
+            config: self.config,
         };
         a.assertions();
 
@@ -207,7 +436,8 @@ impl<'a> Token<'a> {
             line_start: line_start,
             row: row,
             col: col,
-            category: TokenCategory::from(b),
+            category: self.config.find_first_token(b).0,
+            config: self.config,
         };
         b.assertions();
 
@@ -228,10 +458,45 @@ impl<'a> Token<'a> {
     pub fn next_pair(self: Token<'a>) -> (Token<'a>, Option<Token<'a>>) {
         self.assertions();
 
-        let offset = find_first_token(self.string).1;
+        let offset = self.config.find_first_token(self.string).1;
         return self.split_at(offset);
     }
 
+    /// Returns a lazy iterator over every token, including whitespace.
+    ///
+    /// This is the primitive the `Vec`-returning helpers are built on; prefer
+    /// it when you want to stream tokens without allocating a `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let initial = tokens::Token::from("ab;");
+    /// for token in initial.tokens() {
+    ///     print!("{}", token.string);
+    /// }
+    /// ```
+    pub fn tokens(self: Token<'a>) -> Tokens<'a> {
+        return Tokens {
+            remainder: Some(self),
+        };
+    }
+
+    /// Returns a lazy iterator over the tokens, skipping whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let initial = tokens::Token::from("ab ;");
+    /// let strings: Vec<&str> = initial.tokens_no_whitespace().map(|t| t.string).collect();
+    /// assert_eq!(strings, ["ab", ";"]);
+    /// ```
+    pub fn tokens_no_whitespace(self: Token<'a>) -> impl Iterator<Item = Token<'a>> {
+        return self.tokens().filter(|t| match t.category {
+            TokenCategory::Whitespace => false,
+            _ => true,
+        });
+    }
+
     /// Splits an initial token into a vector of tokens, including whitespace
     ///
     /// # Examples
@@ -244,16 +509,7 @@ impl<'a> Token<'a> {
     /// }
     /// ```
     pub fn get_tokens_including_whitespace(self: Token<'a>) -> Vec<Token<'a>> {
-        let (token, remainder) = self.next_pair();
-        return match remainder {
-            Some(remainder) => {
-                let mut a = vec![token];
-                let b = remainder.get_tokens();
-                a.extend(b);
-                a
-            }
-            None => vec![token],
-        };
+        return self.tokens().collect();
     }
 
     /// Splits an initial token into a vector of tokens, including whitespace
@@ -268,11 +524,28 @@ impl<'a> Token<'a> {
     /// }
     /// ```
     pub fn get_tokens(self: Token<'a>) -> Vec<Token<'a>> {
+        return self.tokens_no_whitespace().collect();
+    }
+
+    /// Splits an initial token into a vector of tokens, dropping both
+    /// whitespace and comments so only the semantic tokens remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let initial = tokens::Token::from("a // note\nb");
+    /// let strings: Vec<&str> = initial
+    ///     .get_tokens_no_comments()
+    ///     .into_iter()
+    ///     .map(|t| t.string)
+    ///     .collect();
+    /// assert_eq!(strings, ["a", "b"]);
+    /// ```
+    pub fn get_tokens_no_comments(self: Token<'a>) -> Vec<Token<'a>> {
         return self
-            .get_tokens_including_whitespace()
-            .into_iter()
+            .tokens_no_whitespace()
             .filter(|t| match t.category {
-                TokenCategory::Whitespace => false,
+                TokenCategory::Comment => false,
                 _ => true,
             })
             .collect();
@@ -408,6 +681,122 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_strings_numbers() {
+        let buffer = "x = 40 + 3.14 * 0xFF - 1e10;";
+        let v = Token::from(buffer).get_strings();
+        assert_eq!(
+            v,
+            ["x", "=", "40", "+", "3.14", "*", "0xFF", "-", "1e10", ";",]
+        );
+    }
+
+    #[test]
+    fn number_not_absorbed_by_identifier() {
+        let buffer = "40age";
+        let v = Token::from(buffer).get_strings();
+        assert_eq!(v, ["40", "age"]);
+    }
+
+    #[test]
+    fn unicode_identifiers() {
+        let buffer = "ประเทศไทย中华Việt Nam";
+        let v = Token::from(buffer).get_strings();
+        assert_eq!(v, ["ประเทศไทย中华Việt", "Nam"]);
+    }
+
+    #[test]
+    fn unicode_round_trips() {
+        let buffer = "let x中 = 'café';\n";
+        let joined: String = Token::from(buffer)
+            .get_strings_including_whitespace()
+            .concat();
+        assert_eq!(joined, buffer);
+    }
+
+    #[test]
+    fn string_with_escaped_quote() {
+        let buffer = "\"he said \\\"hi\\\"\" end";
+        let v = Token::from(buffer).get_strings();
+        assert_eq!(v, ["\"he said \\\"hi\\\"\"", "end"]);
+    }
+
+    #[test]
+    fn unterminated_string_is_unknown() {
+        let token = Token::from("\"no close");
+        let (literal, rest) = token.next_pair();
+        assert_eq!(literal.string, "\"no close");
+        assert!(matches!(literal.category, TokenCategory::Unknown));
+        assert!(rest.is_none());
+    }
+
+    #[test]
+    fn char_versus_string_literal() {
+        let (a, _) = Token::from("'a'").next_pair();
+        assert_eq!(a.string, "'a'");
+        assert!(matches!(a.category, TokenCategory::CharLiteral));
+
+        let (s, _) = Token::from("'string'").next_pair();
+        assert_eq!(s.string, "'string'");
+        assert!(matches!(s.category, TokenCategory::StringLiteral));
+    }
+
+    #[test]
+    fn line_comment() {
+        let buffer = "a = 1; // set a\nb = 2;";
+        let all = Token::from(buffer).get_strings();
+        assert!(all.contains(&"// set a"));
+        let code = Token::from(buffer).get_tokens_no_comments();
+        assert!(!code.iter().any(|t| t.string.contains("set a")));
+    }
+
+    #[test]
+    fn block_comment_tracks_rows() {
+        let buffer = "a /* one\ntwo */ b";
+        let (_a, rest) = Token::from(buffer).next_pair();
+        let (_space, rest) = rest.unwrap().next_pair();
+        let (comment, rest) = rest.unwrap().next_pair();
+        assert_eq!(comment.string, "/* one\ntwo */");
+        assert!(matches!(comment.category, TokenCategory::Comment));
+        let (_space, rest) = rest.unwrap().next_pair();
+        let (b, _) = rest.unwrap().next_pair();
+        assert_eq!(b.string, "b");
+        assert_eq!(b.row, 1);
+    }
+
+    #[test]
+    fn unterminated_block_comment_is_unknown() {
+        let (comment, rest) = Token::from("/* open").next_pair();
+        assert_eq!(comment.string, "/* open");
+        assert!(matches!(comment.category, TokenCategory::Unknown));
+        assert!(rest.is_none());
+    }
+
+    #[test]
+    fn custom_config_comment_styles() {
+        // Default treats `#` as a line comment.
+        assert!(Token::from("#foo").get_strings().contains(&"#foo"));
+
+        // A config without the `#` prefix tokenizes it as a symbol instead.
+        let config = Tokenizer {
+            line_comments: vec![String::from("//")],
+            ..Tokenizer::default()
+        };
+        let v: Vec<&str> = Token::from_with("#foo", &config)
+            .get_strings()
+            .into_iter()
+            .collect();
+        assert_eq!(v, ["#", "foo"]);
+    }
+
+    #[test]
+    fn default_matches_longest_sequence_first() {
+        // `Tokenizer::default` sorts sequences longest-first, so `===` wins
+        // over its `==` prefix.
+        let (seq, _) = Token::from("===x").next_pair();
+        assert_eq!(seq.string, "===");
+    }
+
     #[test]
     fn next_simple() {
         let buffer = "age = 40;";